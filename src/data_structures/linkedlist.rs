@@ -1,9 +1,40 @@
 use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::FusedIterator;
 use std::rc::Rc;
 
-/// An implementation of a doubly-linked list. Not thread-safe. Note that the data items contained
-/// within nodes cannot be changed after they have been added to the linked-list.
+/// Error returned when an index passed to a LinkedList method is greater than or equal to the
+/// length of the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfRangeError {
+    index: usize,
+    len: usize,
+}
+
+impl IndexOutOfRangeError {
+    fn new(index: usize, len: usize) -> IndexOutOfRangeError {
+        IndexOutOfRangeError { index, len }
+    }
+}
+
+impl fmt::Display for IndexOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} out of range for LinkedList of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl std::error::Error for IndexOutOfRangeError {}
+
+/// An implementation of a doubly-linked list. Not thread-safe. Data items contained within nodes
+/// are shared via `Rc<RefCell<T>>`, so every handle returned by this API (from `get`, `pop`,
+/// `iter`, ...) can be mutated in place via `.borrow_mut()`, regardless of how many other handles
+/// to the same item are alive.
 pub struct LinkedList<T> {
     head: Link<T>,
     tail: Link<T>,
@@ -56,7 +87,7 @@ impl<T> LinkedList<T> {
 
     /// Removes the last node from the LinkedList. Returns an Option containing the value from the
     /// removed node, otherwise None.
-    pub fn pop(&mut self) -> Option<Rc<T>> {
+    pub fn pop(&mut self) -> Option<Rc<RefCell<T>>> {
         // Handle case for empty list
         if self.head.is_none() && self.tail.is_none() {
             return None;
@@ -73,7 +104,7 @@ impl<T> LinkedList<T> {
 
     /// Removes the first node from the LinkedList. Returns an Option containing the value from the
     /// removed node, otherwise None.
-    pub fn pop_front(&mut self) -> Option<Rc<T>> {
+    pub fn pop_front(&mut self) -> Option<Rc<RefCell<T>>> {
         // Handle case for empty list
         if self.head.is_none() && self.tail.is_none() {
             return None;
@@ -100,7 +131,168 @@ impl<T> LinkedList<T> {
 
     /// Creates an iterator over the LinkedList.
     pub fn iter(&self) -> LinkedListIter<T> {
-        LinkedListIter::new(&self.head)
+        LinkedListIter::new(&self.head, &self.tail, self.len)
+    }
+
+    /// Creates a CursorMut positioned at the front of the LinkedList.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head.clone(),
+            list: self,
+        }
+    }
+
+    /// Creates a CursorMut positioned at the back of the LinkedList.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail.clone(),
+            list: self,
+        }
+    }
+
+    /// Returns a handle to the data item at the front of the LinkedList, allowing it to be updated
+    /// in place via `.borrow_mut()`. Returns None if the list is empty.
+    pub fn front_mut(&mut self) -> Option<Rc<RefCell<T>>> {
+        self.head.as_ref().map(|node| node.borrow().get_data())
+    }
+
+    /// Returns a handle to the data item at the back of the LinkedList. See `front_mut` for the
+    /// only condition (an empty list) under which this returns None.
+    pub fn back_mut(&mut self) -> Option<Rc<RefCell<T>>> {
+        self.tail.as_ref().map(|node| node.borrow().get_data())
+    }
+
+    /// Creates a mutable iterator over the LinkedList. This is an alias for `iter`: since data
+    /// items are stored as `Rc<RefCell<T>>`, every handle yielded by `iter` can already be updated
+    /// in place via `.borrow_mut()`, so there is no need for a separate iterator type here.
+    pub fn iter_mut(&mut self) -> LinkedListIter<T> {
+        self.iter()
+    }
+
+    /// Splits the LinkedList into two at the given index, walking `at` nodes from the head to find
+    /// the split point. Returns a new LinkedList containing the nodes from `at` onwards, leaving
+    /// the nodes before `at` in this list. Panics if `at` is greater than the length of the list.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(at <= self.len, "split index out of bounds");
+        if at == 0 {
+            return std::mem::take(self);
+        }
+        if at == self.len {
+            return LinkedList::new();
+        }
+        // Walk to the node that will become the head of the split-off list
+        let mut split_node = self.head.clone();
+        for _ in 0..at {
+            let next = split_node.as_ref().unwrap().borrow().get_next();
+            split_node = next;
+        }
+        let split_node = split_node.unwrap();
+        let split_prev = split_node.borrow().get_prev();
+        split_prev.as_ref().unwrap().borrow_mut().set_next(&None);
+        split_node.borrow_mut().set_prev(&None);
+        let new_list = LinkedList {
+            head: Some(split_node),
+            tail: self.tail.clone(),
+            len: self.len - at,
+        };
+        self.tail = split_prev;
+        self.len = at;
+        new_list
+    }
+
+    /// Appends all the nodes from `other` onto the end of this LinkedList in constant time,
+    /// leaving `other` empty.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            self.head = other.head.clone();
+        } else {
+            self.tail
+                .as_ref()
+                .unwrap()
+                .borrow_mut()
+                .set_next(&other.head);
+            other
+                .head
+                .as_ref()
+                .unwrap()
+                .borrow_mut()
+                .set_prev(&self.tail);
+        }
+        self.tail = other.tail.clone();
+        self.len += other.len;
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    /// Returns the data item at the given index, otherwise None if the index is out of range.
+    /// Walks from whichever end of the list is closer to the index.
+    pub fn get(&self, index: usize) -> Option<Rc<RefCell<T>>> {
+        if index >= self.len {
+            return None;
+        }
+        Some(self.node_at(index).unwrap().borrow().get_data())
+    }
+
+    /// Inserts a data item at the given index, shifting the items at and after the index one
+    /// position towards the tail. Panics if `index` is greater than the length of the list.
+    pub fn insert(&mut self, index: usize, data: T) {
+        assert!(index <= self.len, "insertion index out of bounds");
+        if index == self.len {
+            self.push(data);
+            return;
+        }
+        self.cursor_at_mut(index).insert_before(data);
+    }
+
+    /// Removes and returns the data item at the given index, shifting the items after the index
+    /// one position towards the head. Returns an IndexOutOfRangeError if `index` is greater than
+    /// or equal to the length of the list.
+    pub fn remove(&mut self, index: usize) -> Result<Rc<RefCell<T>>, IndexOutOfRangeError> {
+        if index >= self.len {
+            return Err(IndexOutOfRangeError::new(index, self.len));
+        }
+        Ok(self.cursor_at_mut(index).remove_current().unwrap())
+    }
+
+    /// Returns the link at the given in-range index, walking from whichever end of the list is
+    /// closer to halve the traversal cost.
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index <= self.len - 1 - index {
+            let mut current = self.head.clone();
+            for _ in 0..index {
+                current = current.unwrap().borrow().get_next();
+            }
+            current
+        } else {
+            let mut current = self.tail.clone();
+            for _ in 0..(self.len - 1 - index) {
+                current = current.unwrap().borrow().get_prev();
+            }
+            current
+        }
+    }
+
+    /// Returns a CursorMut positioned at the given in-range index, walking from whichever end of
+    /// the list is closer to halve the traversal cost.
+    fn cursor_at_mut(&mut self, index: usize) -> CursorMut<'_, T> {
+        if index <= self.len - 1 - index {
+            let mut cursor = self.cursor_front_mut();
+            for _ in 0..index {
+                cursor.move_next();
+            }
+            cursor
+        } else {
+            let steps_from_back = self.len - 1 - index;
+            let mut cursor = self.cursor_back_mut();
+            for _ in 0..steps_from_back {
+                cursor.move_prev();
+            }
+            cursor
+        }
     }
 }
 
@@ -111,7 +303,7 @@ impl<T> Default for LinkedList<T> {
 }
 
 impl<T> IntoIterator for LinkedList<T> {
-    type Item = Rc<T>;
+    type Item = Rc<RefCell<T>>;
 
     type IntoIter = LinkedListIter<T>;
 
@@ -120,12 +312,101 @@ impl<T> IntoIterator for LinkedList<T> {
     }
 }
 
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push(data);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| *a.borrow() == *b.borrow())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut a = self.iter();
+        let mut b = other.iter();
+        loop {
+            return match (a.next(), b.next()) {
+                (Some(x), Some(y)) => match x.borrow().partial_cmp(&*y.borrow()) {
+                    Some(Ordering::Equal) => continue,
+                    non_eq => non_eq,
+                },
+                (None, None) => Some(Ordering::Equal),
+                (None, Some(_)) => Some(Ordering::Less),
+                (Some(_), None) => Some(Ordering::Greater),
+            };
+        }
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut a = self.iter();
+        let mut b = other.iter();
+        loop {
+            return match (a.next(), b.next()) {
+                (Some(x), Some(y)) => match x.borrow().cmp(&*y.borrow()) {
+                    Ordering::Equal => continue,
+                    non_eq => non_eq,
+                },
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+            };
+        }
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for item in self.iter() {
+            item.borrow().hash(state);
+        }
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().map(|item| item.borrow().clone()).collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for item in self.iter() {
+            list.entry(&*item.borrow());
+        }
+        list.finish()
+    }
+}
+
 /// Represents a link from one node to another before or after it.
 type Link<T> = Option<Rc<RefCell<Box<Node<T>>>>>;
 
 /// A node containing a data item and links to
 struct Node<T> {
-    data: Rc<T>,
+    data: Rc<RefCell<T>>,
     prev: Link<T>,
     next: Link<T>,
 }
@@ -135,7 +416,7 @@ impl<T> Node<T> {
     /// to None.
     fn new(data: T) -> Node<T> {
         Node {
-            data: Rc::new(data),
+            data: Rc::new(RefCell::new(data)),
             prev: None,
             next: None,
         }
@@ -161,8 +442,8 @@ impl<T> Node<T> {
         self.next.clone()
     }
 
-    /// Gets the data item contained within the Node via cloning.
-    fn get_data(&self) -> Rc<T> {
+    /// Gets the data item contained within the Node via cloning the `Rc`.
+    fn get_data(&self) -> Rc<RefCell<T>> {
         self.data.clone()
     }
 
@@ -172,30 +453,193 @@ impl<T> Node<T> {
     }
 }
 
-/// Wrapper struct for LinkedList to implement the Iterator trait. Yields cloned values contained in
-/// the nodes of the LinkedList.
+/// A cursor over a LinkedList with mutation of the underlying list structure. A cursor always rests
+/// on a node of the list or on an empty "ghost" element, which it reaches by moving past the back
+/// (or, symmetrically, past the front) of the list; moving past this ghost
+/// element wraps the cursor around to the other end of the list.
+pub struct CursorMut<'a, T> {
+    current: Link<T>,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Moves the cursor to the next node. If the cursor is currently on the ghost element, it moves
+    /// to the front of the list.
+    pub fn move_next(&mut self) {
+        self.current = match &self.current {
+            Some(node) => node.borrow().get_next(),
+            None => self.list.head.clone(),
+        };
+    }
+
+    /// Moves the cursor to the previous node. If the cursor is currently on the ghost element, it
+    /// moves to the back of the list.
+    pub fn move_prev(&mut self) {
+        self.current = match &self.current {
+            Some(node) => node.borrow().get_prev(),
+            None => self.list.tail.clone(),
+        };
+    }
+
+    /// Returns the data item contained in the node that the cursor currently rests on, otherwise
+    /// None if the cursor is on the ghost element.
+    pub fn current(&self) -> Option<Rc<RefCell<T>>> {
+        self.current.as_ref().map(|node| node.borrow().get_data())
+    }
+
+    /// Returns the data item contained in the node after the one the cursor currently rests on,
+    /// without moving the cursor.
+    pub fn peek_next(&self) -> Option<Rc<RefCell<T>>> {
+        match &self.current {
+            Some(node) => node
+                .borrow()
+                .get_next()
+                .as_ref()
+                .map(|next| next.borrow().get_data()),
+            None => self.list.head.as_ref().map(|node| node.borrow().get_data()),
+        }
+    }
+
+    /// Returns the data item contained in the node before the one the cursor currently rests on,
+    /// without moving the cursor.
+    pub fn peek_prev(&self) -> Option<Rc<RefCell<T>>> {
+        match &self.current {
+            Some(node) => node
+                .borrow()
+                .get_prev()
+                .as_ref()
+                .map(|prev| prev.borrow().get_data()),
+            None => self.list.tail.as_ref().map(|node| node.borrow().get_data()),
+        }
+    }
+
+    /// Inserts a new node containing the given data item immediately before the node the cursor
+    /// currently rests on. If the cursor is on the ghost element, the new node is pushed onto the
+    /// back of the list.
+    pub fn insert_before(&mut self, data: T) {
+        let node = match &self.current {
+            Some(node) => node.clone(),
+            None => {
+                self.list.push(data);
+                return;
+            }
+        };
+        let prev = node.borrow().get_prev();
+        let new_node = Node::new_link(data);
+        new_node.as_ref().unwrap().borrow_mut().set_prev(&prev);
+        new_node
+            .as_ref()
+            .unwrap()
+            .borrow_mut()
+            .set_next(&Some(node.clone()));
+        match &prev {
+            Some(prev_node) => prev_node.borrow_mut().set_next(&new_node),
+            None => self.list.head = new_node.clone(),
+        }
+        node.borrow_mut().set_prev(&new_node);
+        self.list.len += 1;
+    }
+
+    /// Inserts a new node containing the given data item immediately after the node the cursor
+    /// currently rests on. If the cursor is on the ghost element, the new node is pushed onto the
+    /// front of the list.
+    pub fn insert_after(&mut self, data: T) {
+        let node = match &self.current {
+            Some(node) => node.clone(),
+            None => {
+                self.list.push_front(data);
+                return;
+            }
+        };
+        let next = node.borrow().get_next();
+        let new_node = Node::new_link(data);
+        new_node.as_ref().unwrap().borrow_mut().set_next(&next);
+        new_node
+            .as_ref()
+            .unwrap()
+            .borrow_mut()
+            .set_prev(&Some(node.clone()));
+        match &next {
+            Some(next_node) => next_node.borrow_mut().set_prev(&new_node),
+            None => self.list.tail = new_node.clone(),
+        }
+        node.borrow_mut().set_next(&new_node);
+        self.list.len += 1;
+    }
+
+    /// Removes the node that the cursor currently rests on, bridging its neighbours together and
+    /// advancing the cursor to the following node (which becomes the ghost element if the removed
+    /// node was the tail). Returns the data item contained in the removed node, otherwise None if
+    /// the cursor is on the ghost element.
+    pub fn remove_current(&mut self) -> Option<Rc<RefCell<T>>> {
+        let node = self.current.clone()?;
+        let prev = node.borrow().get_prev();
+        let next = node.borrow().get_next();
+        match &prev {
+            Some(prev_node) => prev_node.borrow_mut().set_next(&next),
+            None => self.list.head = next.clone(),
+        }
+        match &next {
+            Some(next_node) => next_node.borrow_mut().set_prev(&prev),
+            None => self.list.tail = prev.clone(),
+        }
+        self.list.len -= 1;
+        let data = node.borrow().get_data();
+        self.current = next;
+        Some(data)
+    }
+}
+
+/// Wrapper struct for LinkedList to implement the Iterator and DoubleEndedIterator traits. Yields
+/// shared `Rc<RefCell<T>>` handles to the data items in the nodes of the LinkedList (not copies of
+/// the items), walking from the front cursor forwards
+/// and/or from the back cursor backwards until the two cursors meet.
 pub struct LinkedListIter<T> {
-    cursor: Link<T>,
+    front: Link<T>,
+    back: Link<T>,
+    remaining: usize,
 }
 
 impl<T> LinkedListIter<T> {
-    fn new(cursor: &Link<T>) -> LinkedListIter<T> {
+    fn new(front: &Link<T>, back: &Link<T>, remaining: usize) -> LinkedListIter<T> {
         LinkedListIter {
-            cursor: cursor.clone(),
+            front: front.clone(),
+            back: back.clone(),
+            remaining,
         }
     }
 }
 
 impl<T> Iterator for LinkedListIter<T> {
-    type Item = Rc<T>;
+    type Item = Rc<RefCell<T>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // Check if the iterator has been exhausted
-        self.cursor.as_ref()?;
-        // Get the data to yield and advance the iterator
-        let yield_data = self.cursor.as_ref().unwrap().borrow().get_data();
-        let next_node = self.cursor.as_ref().unwrap().borrow().get_next();
-        self.cursor = next_node;
+        if self.remaining == 0 {
+            return None;
+        }
+        self.front.as_ref()?;
+        // Get the data to yield and advance the front cursor
+        let yield_data = self.front.as_ref().unwrap().borrow().get_data();
+        let next_node = self.front.as_ref().unwrap().borrow().get_next();
+        self.front = next_node;
+        self.remaining -= 1;
+        Some(yield_data)
+    }
+}
+
+impl<T> DoubleEndedIterator for LinkedListIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Check if the iterator has been exhausted
+        if self.remaining == 0 {
+            return None;
+        }
+        self.back.as_ref()?;
+        // Get the data to yield and advance the back cursor
+        let yield_data = self.back.as_ref().unwrap().borrow().get_data();
+        let prev_node = self.back.as_ref().unwrap().borrow().get_prev();
+        self.back = prev_node;
+        self.remaining -= 1;
         Some(yield_data)
     }
 }
@@ -235,7 +679,7 @@ mod tests {
         }
         let values_from_list = new_list
             .iter()
-            .map(|val| *val.as_ref())
+            .map(|val| *val.borrow())
             .collect::<Vec<i32>>();
         assert_eq!(values, values_from_list);
     }
@@ -249,7 +693,7 @@ mod tests {
         }
         let values_from_list = new_list
             .iter()
-            .map(|val| *val.as_ref())
+            .map(|val| *val.borrow())
             .collect::<Vec<i32>>();
         let values = values.iter().rev().copied().collect::<Vec<i32>>();
         assert_eq!(values, values_from_list);
@@ -277,7 +721,7 @@ mod tests {
         }
         let strings_from_list = new_list
             .iter()
-            .map(|val| *val.as_ref())
+            .map(|val| *val.borrow())
             .collect::<Vec<&str>>();
         assert_eq!(strings, strings_from_list);
     }
@@ -291,7 +735,7 @@ mod tests {
         }
         let mut values_from_list: Vec<i32> = vec![];
         for i in new_list {
-            values_from_list.push(*i);
+            values_from_list.push(*i.borrow());
         }
         assert_eq!(values, values_from_list);
     }
@@ -314,6 +758,296 @@ mod tests {
         assert_eq!(new_list.len(), 10000000);
     }
 
+    #[test]
+    fn test_cursor_insert_before_after() {
+        let mut new_list = LinkedList::<i32>::new();
+        new_list.push(1);
+        new_list.push(3);
+        let mut cursor = new_list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_before(2);
+        cursor.insert_after(4);
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+        assert_eq!(new_list.len(), 4);
+    }
+
+    #[test]
+    fn test_cursor_remove_current() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..5 {
+            new_list.push(i);
+        }
+        let mut cursor = new_list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(Rc::new(RefCell::new(2))));
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, vec![0, 1, 3, 4]);
+        assert_eq!(new_list.len(), 4);
+    }
+
+    #[test]
+    fn test_cursor_wraps_past_ends() {
+        let mut new_list = LinkedList::<i32>::new();
+        new_list.push(1);
+        new_list.push(2);
+        let mut cursor = new_list.cursor_back_mut();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(Rc::new(RefCell::new(1))));
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut new_list = LinkedList::<i32>::new();
+        let values = (0..10).collect::<Vec<i32>>();
+        for &i in values.iter() {
+            new_list.push(i);
+        }
+        let reversed = new_list.iter().rev().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        let expected = values.iter().rev().copied().collect::<Vec<i32>>();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn test_iter_meet_in_middle() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..6 {
+            new_list.push(i);
+        }
+        let mut iter = new_list.iter();
+        assert_eq!(iter.next(), Some(Rc::new(RefCell::new(0))));
+        assert_eq!(iter.next_back(), Some(Rc::new(RefCell::new(5))));
+        assert_eq!(iter.next(), Some(Rc::new(RefCell::new(1))));
+        assert_eq!(iter.next_back(), Some(Rc::new(RefCell::new(4))));
+        assert_eq!(iter.next(), Some(Rc::new(RefCell::new(2))));
+        assert_eq!(iter.next_back(), Some(Rc::new(RefCell::new(3))));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..6 {
+            new_list.push(i);
+        }
+        let split = new_list.split_off(3);
+        assert_eq!(new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>(), vec![0, 1, 2]);
+        assert_eq!(split.iter().map(|v| *v.borrow()).collect::<Vec<i32>>(), vec![3, 4, 5]);
+        assert_eq!(new_list.len(), 3);
+        assert_eq!(split.len(), 3);
+    }
+
+    #[test]
+    fn test_split_off_boundaries() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..3 {
+            new_list.push(i);
+        }
+        let all = new_list.split_off(0);
+        assert!(new_list.is_empty());
+        assert_eq!(all.len(), 3);
+
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..3 {
+            new_list.push(i);
+        }
+        let empty = new_list.split_off(3);
+        assert!(empty.is_empty());
+        assert_eq!(new_list.len(), 3);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list_a = LinkedList::<i32>::new();
+        let mut list_b = LinkedList::<i32>::new();
+        for i in 0..3 {
+            list_a.push(i);
+        }
+        for i in 3..6 {
+            list_b.push(i);
+        }
+        list_a.append(&mut list_b);
+        assert_eq!(
+            list_a.iter().map(|v| *v.borrow()).collect::<Vec<i32>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(list_a.len(), 6);
+        assert!(list_b.is_empty());
+        assert_eq!(list_b.len(), 0);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..5 {
+            new_list.push(i);
+        }
+        assert_eq!(new_list.get(0), Some(Rc::new(RefCell::new(0))));
+        assert_eq!(new_list.get(4), Some(Rc::new(RefCell::new(4))));
+        assert_eq!(new_list.get(5), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in [1, 2, 4] {
+            new_list.push(i);
+        }
+        new_list.insert(2, 3);
+        new_list.insert(0, 0);
+        new_list.insert(5, 5);
+        assert_eq!(
+            new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(new_list.len(), 6);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..5 {
+            new_list.push(i);
+        }
+        assert_eq!(new_list.remove(2), Ok(Rc::new(RefCell::new(2))));
+        assert_eq!(
+            new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>(),
+            vec![0, 1, 3, 4]
+        );
+        assert_eq!(new_list.len(), 4);
+        assert_eq!(
+            new_list.remove(10),
+            Err(IndexOutOfRangeError::new(10, 4))
+        );
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let new_list: LinkedList<i32> = (0..10).collect();
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, (0..10).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut new_list = LinkedList::<i32>::new();
+        new_list.push(0);
+        new_list.extend(1..4);
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_eq() {
+        let list_a: LinkedList<i32> = (0..5).collect();
+        let list_b: LinkedList<i32> = (0..5).collect();
+        let list_c: LinkedList<i32> = (0..4).collect();
+        assert_eq!(list_a, list_b);
+        assert_ne!(list_a, list_c);
+    }
+
+    #[test]
+    fn test_ord() {
+        let list_a: LinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let list_b: LinkedList<i32> = vec![1, 2, 4].into_iter().collect();
+        assert!(list_a < list_b);
+        assert_eq!(list_a.cmp(&list_a.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::Hasher;
+        let list_a: LinkedList<i32> = (0..5).collect();
+        let list_b: LinkedList<i32> = (0..5).collect();
+        let mut hasher_a = DefaultHasher::new();
+        let mut hasher_b = DefaultHasher::new();
+        list_a.hash(&mut hasher_a);
+        list_b.hash(&mut hasher_b);
+        assert_eq!(hasher_a.finish(), hasher_b.finish());
+    }
+
+    #[test]
+    fn test_clone() {
+        let original: LinkedList<i32> = (0..5).collect();
+        let cloned = original.clone();
+        assert_eq!(original, cloned);
+    }
+
+    #[test]
+    fn test_debug() {
+        let new_list: LinkedList<i32> = (0..3).collect();
+        assert_eq!(format!("{:?}", new_list), "[0, 1, 2]");
+    }
+
+    #[test]
+    fn test_front_back_mut() {
+        let mut new_list = LinkedList::<i32>::new();
+        new_list.push(1);
+        new_list.push(2);
+        new_list.push(3);
+        *new_list.front_mut().unwrap().borrow_mut() = 10;
+        *new_list.back_mut().unwrap().borrow_mut() = 30;
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, vec![10, 2, 30]);
+    }
+
+    #[test]
+    fn test_front_back_mut_empty_list() {
+        let mut new_list = LinkedList::<i32>::new();
+        assert!(new_list.front_mut().is_none());
+        assert!(new_list.back_mut().is_none());
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..5 {
+            new_list.push(i);
+        }
+        for item in new_list.iter_mut() {
+            *item.borrow_mut() *= 10;
+        }
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, vec![0, 10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_iter_mut_updates_even_when_data_pinned() {
+        let mut new_list = LinkedList::<i32>::new();
+        for i in 0..5 {
+            new_list.push(i);
+        }
+        // Hold an outstanding handle to index 2's data item, as an ordinary caller of
+        // get/iter/pop might do, and make sure it still gets updated in place around it: data
+        // items are `Rc<RefCell<T>>`, so mutation through `.borrow_mut()` never depends on how
+        // many other handles to the same item are alive.
+        let pinned = new_list.get(2);
+        for item in new_list.iter_mut() {
+            *item.borrow_mut() *= 100;
+        }
+        let values = new_list.iter().map(|v| *v.borrow()).collect::<Vec<i32>>();
+        assert_eq!(values, vec![0, 100, 200, 300, 400]);
+        assert_eq!(*pinned.unwrap().borrow(), 200);
+    }
+
+    #[test]
+    fn test_front_back_mut_some_even_when_data_pinned() {
+        let mut new_list = LinkedList::<i32>::new();
+        new_list.push(1);
+        new_list.push(2);
+        let pinned_front = new_list.get(0);
+        let pinned_back = new_list.get(1);
+        *new_list.front_mut().unwrap().borrow_mut() = 10;
+        *new_list.back_mut().unwrap().borrow_mut() = 20;
+        assert_eq!(*pinned_front.unwrap().borrow(), 10);
+        assert_eq!(*pinned_back.unwrap().borrow(), 20);
+    }
+
     #[test]
     fn test_array_push() {
         let mut new_list = LinkedList::<[i32; 3]>::new();
@@ -323,7 +1057,7 @@ mod tests {
         }
         let arrays_from_list = new_list
             .iter()
-            .map(|a| *a.as_ref())
+            .map(|a| *a.borrow())
             .collect::<Vec<[i32; 3]>>();
         assert_eq!(arrays, arrays_from_list);
     }